@@ -0,0 +1,55 @@
+//! Mints `mint_lords` to the configured account, the same flow the crate used to run
+//! directly from `main` before the CLI (`call`/`invoke`/`deploy`) took over `src/main.rs`.
+//!
+//! Run with:
+//!
+//! ```sh
+//! cargo run --example mint_lords
+//! ```
+
+use starknet::accounts::Account;
+use starknet::core::types::{BlockId, BlockTag, Call};
+use starknet::core::utils::get_selector_from_name;
+
+use starknet_caller::calldata::{self, CalldataArg, CalldataBuilder};
+use starknet_caller::{starknet_account, starknet_call, starknet_call_context, FeeSettings};
+
+#[tokio::main]
+async fn main() {
+    let context = starknet_call_context()
+        .await
+        .expect("failed to build starknet context");
+    let mut account = starknet_account(
+        context.provider,
+        context.signer,
+        context.address,
+        context.chain_id,
+    );
+    account.set_block_id(BlockId::Tag(BlockTag::Pending));
+
+    let contract_address = starknet::core::types::Felt::from_hex(
+        &std::env::var("STARKNET_CONTRACT_ADDRESS")
+            .expect("cannot find STARKNET_CONTRACT_ADDRESS env"),
+    )
+    .expect("invalid STARKNET_CONTRACT_ADDRESS");
+
+    let mint_amount = calldata::u256_from_decimal("1000000000000000000000")
+        .expect("mint amount does not fit in a u256");
+    let calldata = CalldataBuilder::new()
+        .arg(CalldataArg::Address(context.address))
+        .arg(CalldataArg::U256(mint_amount.0, mint_amount.1))
+        .build()
+        .expect("failed to encode mint_lords calldata");
+
+    let call = vec![Call {
+        to: contract_address,
+        selector: get_selector_from_name("mint_lords").expect("invalid selector"),
+        calldata,
+    }];
+
+    let result = starknet_call(&account, call, FeeSettings::strk_auto())
+        .await
+        .expect("starknet_call failed");
+
+    println!("Transaction hash: {:#064x}", result.transaction_hash);
+}
@@ -0,0 +1,122 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use starknet::accounts::{AccountFactory, OpenZeppelinAccountFactory};
+use starknet::core::types::{BlockId, BlockTag, DeployAccountTransactionResult, Felt};
+use starknet::core::utils::{get_contract_address, get_selector_from_name};
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::JsonRpcClient;
+use starknet::signers::{LocalWallet, SigningKey};
+
+use crate::error::StarknetError;
+use crate::query;
+
+/// Class hash of the OpenZeppelin account contract burners are deployed against by
+/// default. Pass a different `class_hash` to [`generate_burner`] to target another
+/// account class (Argent, Braavos, ...).
+pub const DEFAULT_ACCOUNT_CLASS_HASH: Felt =
+    Felt::from_hex_unchecked("0x061dac032f228abef9c6626f995015233097ae253a7f72d68552db02f2971b8f");
+
+/// The STRK fee token's address, identical across mainnet and sepolia since it was
+/// deployed deterministically. Used to fund-check a burner before deploying it.
+const STRK_TOKEN_ADDRESS: Felt =
+    Felt::from_hex_unchecked("0x04718f5a0fc34cc1af16a1cdee98ffb20c31f5cd61d6ab07201858f4287c938d");
+
+/// A throwaway account: its signing key, counterfactual address, and the account class
+/// it will deploy as. Not yet deployed until [`deploy_account`] sends its
+/// `DEPLOY_ACCOUNT` transaction; until then `address` only holds funds, it has no code.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Burner {
+    pub private_key: Felt,
+    pub address: Felt,
+    pub class_hash: Felt,
+    pub salt: Felt,
+}
+
+/// Generates a fresh signing key and derives its counterfactual address for
+/// `class_hash`, without sending anything on-chain yet. Fund `burner.address` before
+/// calling [`deploy_account`].
+pub fn generate_burner(class_hash: Felt) -> Burner {
+    let signing_key = SigningKey::from_random();
+    let salt = SigningKey::from_random().secret_scalar();
+    let constructor_calldata = [signing_key.verifying_key().scalar()];
+    let address = get_contract_address(salt, class_hash, &constructor_calldata, Felt::ZERO);
+
+    Burner {
+        private_key: signing_key.secret_scalar(),
+        address,
+        class_hash,
+        salt,
+    }
+}
+
+/// Sends a `DEPLOY_ACCOUNT` transaction for `burner`, turning its counterfactual address
+/// into a real, usable account. Fails fast if the address has no STRK balance to pay for
+/// the transaction, since an unfunded burner would just have its deployment rejected.
+pub async fn deploy_account(
+    provider: JsonRpcClient<HttpTransport>,
+    burner: &Burner,
+    chain_id: Felt,
+) -> Result<DeployAccountTransactionResult, StarknetError> {
+    let balance_selector =
+        get_selector_from_name("balanceOf").expect("balanceOf is a valid selector");
+    let balance = query::call(
+        &provider,
+        STRK_TOKEN_ADDRESS,
+        balance_selector,
+        vec![burner.address],
+        BlockId::Tag(BlockTag::Latest),
+    )
+    .await?;
+    // `balanceOf` returns a u256 as `[low, high]`; a balance that's an exact multiple of
+    // 2^128 has `low == 0`, so every returned felt must be checked, not just the first.
+    if balance.iter().all(|felt| *felt == Felt::ZERO) {
+        return Err(StarknetError::Config(format!(
+            "burner {:#064x} has no STRK balance to pay for its DEPLOY_ACCOUNT transaction",
+            burner.address
+        )));
+    }
+
+    let signer = LocalWallet::from(SigningKey::from_secret_scalar(burner.private_key));
+    let factory = OpenZeppelinAccountFactory::new(burner.class_hash, chain_id, signer, provider)
+        .await
+        .map_err(StarknetError::Provider)?;
+
+    let result = factory
+        .deploy_v3(burner.salt)
+        .send()
+        .await
+        .map_err(|err| StarknetError::Config(format!("failed to deploy burner account: {err}")))?;
+
+    Ok(result)
+}
+
+/// Loads the burner keystore at `path`, returning an empty list if it doesn't exist yet.
+pub fn load_keystore(path: impl AsRef<Path>) -> Result<Vec<Burner>, StarknetError> {
+    let path = path.as_ref();
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let contents = std::fs::read_to_string(path).map_err(|err| {
+        StarknetError::Config(format!("failed to read keystore {}: {err}", path.display()))
+    })?;
+    serde_json::from_str(&contents)
+        .map_err(|err| StarknetError::Config(format!("failed to parse keystore: {err}")))
+}
+
+/// Appends `burner` to the keystore at `path`, creating it if necessary, so throwaway
+/// accounts can be reloaded in a later run instead of regenerated (and re-funded) each
+/// time.
+pub fn persist_burner(path: impl AsRef<Path>, burner: &Burner) -> Result<(), StarknetError> {
+    let path = path.as_ref();
+    let mut burners = load_keystore(path)?;
+    burners.push(burner.clone());
+    let contents = serde_json::to_string_pretty(&burners)
+        .map_err(|err| StarknetError::Config(format!("failed to serialize keystore: {err}")))?;
+    std::fs::write(path, contents).map_err(|err| {
+        StarknetError::Config(format!(
+            "failed to write keystore {}: {err}",
+            path.display()
+        ))
+    })
+}
@@ -0,0 +1,209 @@
+use starknet::core::types::Felt;
+use starknet::core::utils::cairo_short_string_to_felt;
+
+use crate::error::StarknetError;
+
+/// Looks up `function_name` in a Sierra ABI (the `abi` array of a contract artifact JSON)
+/// and checks that `arg_count` typed arguments were supplied for it, so a mismatched
+/// calldata builder fails fast instead of sending a transaction the contract will revert.
+///
+/// Only the input *count* is checked; validating each input's Cairo type against the
+/// matching `CalldataArg` variant is left for a follow-up, since the ABI's type strings
+/// (`core::integer::u256`, `core::starknet::contract_address::ContractAddress`, ...) need
+/// a small parser of their own.
+pub fn validate_arg_count(
+    abi: &serde_json::Value,
+    function_name: &str,
+    arg_count: usize,
+) -> Result<(), StarknetError> {
+    let entries = abi.as_array().ok_or_else(|| {
+        StarknetError::Config("contract ABI is not a JSON array".into())
+    })?;
+
+    let function = entries
+        .iter()
+        .filter(|entry| entry.get("type").and_then(|t| t.as_str()) == Some("function"))
+        .find(|entry| entry.get("name").and_then(|n| n.as_str()) == Some(function_name))
+        .ok_or_else(|| {
+            StarknetError::Config(format!("function \"{function_name}\" not found in ABI"))
+        })?;
+
+    let expected = function
+        .get("inputs")
+        .and_then(|inputs| inputs.as_array())
+        .map(|inputs| inputs.len())
+        .unwrap_or(0);
+
+    if expected != arg_count {
+        return Err(StarknetError::Config(format!(
+            "\"{function_name}\" expects {expected} argument(s), got {arg_count}"
+        )));
+    }
+
+    Ok(())
+}
+
+/// A typed argument to append to a [`CalldataBuilder`].
+///
+/// Each variant knows how many `Felt`s it expands to under Starknet's flat calldata
+/// encoding, so callers don't have to hand-encode u256 low/high pairs or array length
+/// prefixes themselves.
+#[derive(Debug, Clone)]
+pub enum CalldataArg {
+    /// A single `Felt`, encoded as-is.
+    Felt(Felt),
+    /// A contract address, encoded as a single `Felt`.
+    Address(Felt),
+    /// A `u256`, encoded as `[low, high]`.
+    U256(u128, u128),
+    /// A `bool`, encoded as `Felt::ZERO` or `Felt::ONE`.
+    Bool(bool),
+    /// A short string (<= 31 ASCII bytes), encoded as a single packed `Felt`.
+    ShortString(String),
+    /// An array, encoded as a length-prefixed run of its flattened elements.
+    Array(Vec<CalldataArg>),
+}
+
+impl CalldataArg {
+    /// Appends this argument's flat encoding onto `out`.
+    fn encode_into(&self, out: &mut Vec<Felt>) -> Result<(), StarknetError> {
+        match self {
+            CalldataArg::Felt(felt) => out.push(*felt),
+            CalldataArg::Address(address) => out.push(*address),
+            CalldataArg::U256(low, high) => {
+                out.push(Felt::from(*low));
+                out.push(Felt::from(*high));
+            }
+            CalldataArg::Bool(value) => out.push(if *value { Felt::ONE } else { Felt::ZERO }),
+            CalldataArg::ShortString(value) => {
+                let felt = cairo_short_string_to_felt(value).map_err(|_| {
+                    StarknetError::Config(format!(
+                        "\"{value}\" is not a valid short string (must be <= 31 ASCII bytes)"
+                    ))
+                })?;
+                out.push(felt);
+            }
+            CalldataArg::Array(elements) => {
+                out.push(Felt::from(elements.len() as u64));
+                for element in elements {
+                    element.encode_into(out)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Builds a `Vec<Felt>` calldata payload from typed arguments, following Starknet's flat
+/// calldata encoding (the same rules `snforge`/`sncast` use): a `u256` expands to
+/// `[low, high]`, an array is length-prefixed, and so on.
+///
+/// # Example
+///
+/// ```
+/// # use starknet::core::types::Felt;
+/// # use starknet_caller::calldata::{CalldataArg, CalldataBuilder};
+/// # let recipient = Felt::from(1u8);
+/// # let (low, high) = (1_000u128, 0u128);
+/// // mint_lords(recipient: ContractAddress, amount: u256)
+/// let calldata = CalldataBuilder::new()
+///     .arg(CalldataArg::Address(recipient))
+///     .arg(CalldataArg::U256(low, high))
+///     .build();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CalldataBuilder {
+    args: Vec<CalldataArg>,
+}
+
+impl CalldataBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends one typed argument.
+    pub fn arg(mut self, arg: CalldataArg) -> Self {
+        self.args.push(arg);
+        self
+    }
+
+    /// Flattens every argument added so far into the final `Vec<Felt>` calldata.
+    pub fn build(self) -> Result<Vec<Felt>, StarknetError> {
+        let mut out = Vec::new();
+        for arg in &self.args {
+            arg.encode_into(&mut out)?;
+        }
+        Ok(out)
+    }
+}
+
+/// Multiplies a 128-bit value by 10, returning `(low_128_bits, carry)` of the full
+/// (up to 132-bit) result. `overflowing_mul` alone can't express this: it only reports
+/// *whether* the product overflowed 128 bits, not the carry's actual value, which for a
+/// multiply-by-10 can be anywhere from 0 to 9. Splitting `value` into 64-bit halves keeps
+/// every intermediate product within `u128`, so no wider integer type is needed.
+fn mul10(value: u128) -> (u128, u128) {
+    let lo = value & u128::from(u64::MAX);
+    let hi = value >> 64;
+
+    let prod_lo = lo * 10;
+    let carry_from_lo = prod_lo >> 64;
+    let new_lo = prod_lo & u128::from(u64::MAX);
+
+    let prod_hi = hi * 10 + carry_from_lo;
+    let carry = prod_hi >> 64;
+    let new_hi = prod_hi & u128::from(u64::MAX);
+
+    ((new_hi << 64) | new_lo, carry)
+}
+
+/// Splits a `u256` given as a decimal string into its `(low, high)` 128-bit halves.
+///
+/// This mirrors how the mint example in the docs expands an amount like
+/// `1000000000000000000000` into the two felts a `u256` parameter expects. Parsing is
+/// done digit-by-digit so values larger than `u128::MAX` (but still within `u256`) are
+/// supported, unlike a plain `str::parse::<u128>()`.
+pub fn u256_from_decimal(amount: &str) -> Result<(u128, u128), StarknetError> {
+    if amount.is_empty() || !amount.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(StarknetError::Config(format!(
+            "\"{amount}\" is not a valid u256 amount"
+        )));
+    }
+
+    let (mut low, mut high) = (0u128, 0u128);
+    for byte in amount.bytes() {
+        let digit = u128::from(byte - b'0');
+        let (scaled_low, mul_carry) = mul10(low);
+        let (new_low, overflowed_add) = scaled_low.overflowing_add(digit);
+        let carry = mul_carry + u128::from(overflowed_add);
+        high = high
+            .checked_mul(10)
+            .and_then(|h| h.checked_add(carry))
+            .ok_or_else(|| StarknetError::Config(format!("\"{amount}\" overflows a u256")))?;
+        low = new_low;
+    }
+    Ok((low, high))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn u256_from_decimal_splits_values_below_u128_max() {
+        assert_eq!(u256_from_decimal("0").unwrap(), (0, 0));
+        assert_eq!(
+            u256_from_decimal("1000000000000000000000").unwrap(),
+            (1_000_000_000_000_000_000_000u128, 0)
+        );
+    }
+
+    #[test]
+    fn u256_from_decimal_carries_correctly_above_2_pow_127() {
+        // 5 * 2^127 = 2 * 2^128 + 2^127, i.e. high = 2, low = 2^127.
+        let (low, high) =
+            u256_from_decimal("850705917302346158658436518579420528640").unwrap();
+        assert_eq!(low, 1u128 << 127);
+        assert_eq!(high, 2);
+    }
+}
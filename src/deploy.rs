@@ -0,0 +1,90 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use starknet::accounts::{Account, SingleOwnerAccount};
+use starknet::contract::ContractFactory;
+use starknet::core::types::contract::{CompiledClass, SierraClass};
+use starknet::core::types::{DeclareTransactionResult, Felt};
+use starknet::core::utils::get_contract_address;
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::JsonRpcClient;
+use starknet::signers::LocalWallet;
+
+use crate::error::StarknetError;
+
+/// A Starknet account as used throughout the deployment subsystem.
+type DeployAccount = SingleOwnerAccount<JsonRpcClient<HttpTransport>, LocalWallet>;
+
+/// Reads the Sierra artifact at `path`, declares it on `account`, and returns both the
+/// declare transaction result and the class hash so the caller can immediately deploy it.
+pub async fn declare_contract(
+    account: &DeployAccount,
+    path_to_artifact: impl AsRef<Path>,
+    compiled_class_hash: Felt,
+) -> Result<(DeclareTransactionResult, Felt), StarknetError> {
+    let artifact = std::fs::read_to_string(path_to_artifact.as_ref()).map_err(|err| {
+        StarknetError::Config(format!(
+            "failed to read contract artifact {}: {err}",
+            path_to_artifact.as_ref().display()
+        ))
+    })?;
+    let contract: SierraClass = serde_json::from_str(&artifact).map_err(|err| {
+        StarknetError::Config(format!("failed to parse Sierra artifact: {err}"))
+    })?;
+    let flattened = contract.flatten().map_err(|err| {
+        StarknetError::Config(format!("failed to flatten Sierra artifact: {err}"))
+    })?;
+    let class_hash = flattened.class_hash();
+
+    let result = account
+        .declare_v3(Arc::new(flattened), compiled_class_hash)
+        .send()
+        .await?;
+
+    Ok((result, class_hash))
+}
+
+/// Deploys a new instance of `class_hash` with `constructor_calldata`, using `salt` to
+/// derive the deployed address. Built on top of [`ContractFactory`], the same builder
+/// the upstream `starknet-rs` examples use.
+pub async fn deploy_contract(
+    account: DeployAccount,
+    class_hash: Felt,
+    constructor_calldata: Vec<Felt>,
+    salt: Felt,
+) -> Result<Felt, StarknetError> {
+    let factory = ContractFactory::new(class_hash, account);
+
+    let deployment = factory.deploy_v3(constructor_calldata.clone(), salt, false);
+    let deployed_address = deployment.deployed_address();
+    deployment.send().await?;
+
+    Ok(deployed_address)
+}
+
+/// Predicts the address a `DEPLOY`/`DEPLOY_ACCOUNT` transaction would produce, without
+/// sending anything. Mirrors `starknet::core::utils::get_contract_address`, which uses
+/// the same `pedersen`-based formula the sequencer does.
+pub fn compute_contract_address(
+    class_hash: Felt,
+    salt: Felt,
+    constructor_calldata: &[Felt],
+    deployer_address: Felt,
+) -> Felt {
+    get_contract_address(salt, class_hash, constructor_calldata, deployer_address)
+}
+
+/// Reads a CASM artifact at `path` and returns its compiled class hash, needed alongside
+/// the Sierra class hash when declaring a contract.
+pub fn compiled_class_hash_from_file(path: impl AsRef<Path>) -> Result<Felt, StarknetError> {
+    let artifact = std::fs::read_to_string(path.as_ref()).map_err(|err| {
+        StarknetError::Config(format!(
+            "failed to read CASM artifact {}: {err}",
+            path.as_ref().display()
+        ))
+    })?;
+    let casm: CompiledClass = serde_json::from_str(&artifact)
+        .map_err(|err| StarknetError::Config(format!("failed to parse CASM artifact: {err}")))?;
+    casm.class_hash()
+        .map_err(|err| StarknetError::Config(format!("failed to hash compiled class: {err}")))
+}
@@ -0,0 +1,20 @@
+use starknet::providers::ProviderError;
+
+/// Crate-wide error type returned by every fallible Starknet operation.
+///
+/// This replaces the `unwrap()`/`expect()` panics that the crate used to rely on,
+/// letting callers decide how to react to a failed RPC call or bad configuration.
+#[derive(Debug, thiserror::Error)]
+pub enum StarknetError {
+    /// The JSON-RPC provider returned an error (node rejected the request, contract
+    /// reverted, network unreachable, etc).
+    #[error("starknet provider error: {0}")]
+    Provider(#[from] ProviderError),
+
+    /// Invalid configuration or input: a missing or unparsable environment variable, an
+    /// unreadable or malformed artifact, ABI or keystore file, calldata that doesn't
+    /// encode or match the ABI, zero fee bounds, an unfunded burner, or a failed
+    /// account deployment.
+    #[error("invalid configuration: {0}")]
+    Config(String),
+}
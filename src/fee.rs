@@ -0,0 +1,138 @@
+use starknet::accounts::{Account, SingleOwnerAccount};
+use starknet::core::types::{Call, Felt, InvokeTransactionResult};
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::JsonRpcClient;
+use starknet::signers::LocalWallet;
+
+use crate::error::StarknetError;
+
+/// If a raw fee estimate (before the multiplier is applied) exceeds this many
+/// wei/fri, `starknet_call` still sends the transaction but prints a warning, since an
+/// estimate this large for a single call is usually a sign of a misbehaving node rather
+/// than a real price spike.
+const ESTIMATE_WARN_CEILING: u128 = 1_000_000_000_000_000_000;
+
+/// Chooses which fee token and transaction version `starknet_call` pays with.
+///
+/// `Eth` sends a v1 transaction paying `max_fee` in ETH (wei); `Strk` sends a v3
+/// transaction with explicit L1 gas resource bounds paid in STRK (fri). Passing `None`
+/// for the bound(s) runs `estimate_fee` first and pads the result by `multiplier`.
+#[derive(Debug, Clone)]
+pub enum FeeSettings {
+    Eth {
+        max_fee: Option<Felt>,
+        multiplier: f64,
+    },
+    Strk {
+        max_gas: Option<u64>,
+        max_gas_unit_price: Option<u128>,
+        multiplier: f64,
+    },
+}
+
+impl FeeSettings {
+    /// STRK resource bounds derived from a live estimate, padded by 50%.
+    pub fn strk_auto() -> Self {
+        FeeSettings::Strk {
+            max_gas: None,
+            max_gas_unit_price: None,
+            multiplier: 1.5,
+        }
+    }
+
+    /// ETH `max_fee` derived from a live estimate, padded by 50%.
+    pub fn eth_auto() -> Self {
+        FeeSettings::Eth {
+            max_fee: None,
+            multiplier: 1.5,
+        }
+    }
+}
+
+/// Scales `estimate` by `multiplier`, rejecting a zero result and warning if the raw
+/// estimate itself (not the multiplier) looks implausibly large.
+fn apply_multiplier(estimate: u128, multiplier: f64) -> Result<u128, StarknetError> {
+    if estimate > ESTIMATE_WARN_CEILING {
+        eprintln!(
+            "warning: fee estimate {estimate} exceeds the sanity ceiling of {ESTIMATE_WARN_CEILING} (is the node misbehaving?)"
+        );
+    }
+    let scaled = (estimate as f64 * multiplier) as u128;
+    if scaled == 0 {
+        return Err(StarknetError::Config(
+            "estimated fee is zero, refusing to send with a zero bound".into(),
+        ));
+    }
+    Ok(scaled)
+}
+
+/// Executes `calls` on `account`, paying fees according to `fee_settings`.
+pub async fn execute_with_fee_settings(
+    account: &SingleOwnerAccount<JsonRpcClient<HttpTransport>, LocalWallet>,
+    calls: Vec<Call>,
+    fee_settings: FeeSettings,
+) -> Result<InvokeTransactionResult, StarknetError> {
+    match fee_settings {
+        FeeSettings::Eth { max_fee, multiplier } => {
+            let execution = account.execute_v1(calls);
+            let max_fee = match max_fee {
+                Some(fee) => fee,
+                None => {
+                    let estimate = execution.estimate_fee().await?;
+                    let overall_fee: u128 = estimate.overall_fee.to_string().parse().unwrap_or(0);
+                    Felt::from(apply_multiplier(overall_fee, multiplier)?)
+                }
+            };
+            if max_fee == Felt::ZERO {
+                return Err(StarknetError::Config(
+                    "max_fee must be non-zero".into(),
+                ));
+            }
+            let result = execution.max_fee(max_fee).send().await?;
+            Ok(result)
+        }
+        FeeSettings::Strk {
+            max_gas,
+            max_gas_unit_price,
+            multiplier,
+        } => {
+            let execution = account.execute_v3(calls);
+            // Only estimate the bound(s) the caller left unset, so an explicit `max_gas`
+            // or `max_gas_unit_price` is never silently overridden by an estimate.
+            let (max_gas, max_gas_unit_price) = if max_gas.is_none() || max_gas_unit_price.is_none()
+            {
+                let estimate = execution.estimate_fee().await?;
+                let overall_fee: u128 = estimate.overall_fee.to_string().parse().unwrap_or(0);
+                if overall_fee > ESTIMATE_WARN_CEILING {
+                    eprintln!(
+                        "warning: fee estimate {overall_fee} exceeds the sanity ceiling of {ESTIMATE_WARN_CEILING} (is the node misbehaving?)"
+                    );
+                }
+                let estimated_gas: u128 = estimate.gas_consumed.to_string().parse().unwrap_or(0);
+                let estimated_price: u128 = estimate.gas_price.to_string().parse().unwrap_or(0);
+                let gas = match max_gas {
+                    Some(gas) => gas,
+                    None => apply_multiplier(estimated_gas, multiplier)? as u64,
+                };
+                let price = match max_gas_unit_price {
+                    Some(price) => price,
+                    None => apply_multiplier(estimated_price, multiplier)?,
+                };
+                (gas, price)
+            } else {
+                (max_gas.unwrap(), max_gas_unit_price.unwrap())
+            };
+            if max_gas == 0 || max_gas_unit_price == 0 {
+                return Err(StarknetError::Config(
+                    "max_gas and max_gas_unit_price must be non-zero".into(),
+                ));
+            }
+            let result = execution
+                .gas(max_gas)
+                .gas_price(max_gas_unit_price)
+                .send()
+                .await?;
+            Ok(result)
+        }
+    }
+}
@@ -0,0 +1,138 @@
+//! Library surface for programmatic Starknet interaction: context/account setup, a
+//! read-only query API ([`query`]), configurable fee strategies ([`fee`]), ABI-aware
+//! calldata encoding ([`calldata`]), and contract declaration/deployment ([`deploy`]).
+//!
+//! The accompanying binary (`src/main.rs`) wraps these functions in a `clap`-based CLI
+//! with `call`/`invoke`/`deploy` subcommands.
+
+pub mod burner;
+pub mod calldata;
+pub mod deploy;
+pub mod error;
+pub mod fee;
+pub mod network;
+pub mod query;
+
+use starknet::accounts::{Account, ExecutionEncoding, SingleOwnerAccount};
+use starknet::core::types::{Call, Felt, InvokeTransactionResult};
+use starknet::providers::jsonrpc::{HttpTransport, JsonRpcClient};
+use starknet::providers::Url;
+use starknet::signers::{LocalWallet, SigningKey};
+
+pub use error::StarknetError;
+pub use fee::FeeSettings;
+pub use network::Network;
+
+/// The concrete account type every function in this crate operates on.
+pub type StarknetAccount = SingleOwnerAccount<JsonRpcClient<HttpTransport>, LocalWallet>;
+
+/// A structure to hold Starknet connection context information.
+/// This groups the necessary components for interacting with Starknet.
+pub struct StarknetContext {
+    /// JSON-RPC client for communicating with a Starknet node
+    pub provider: JsonRpcClient<HttpTransport>,
+    /// Local wallet used for signing transactions
+    pub signer: LocalWallet,
+    /// Starknet account address
+    pub address: Felt,
+    /// Chain id to sign transactions for, either the network's default or one detected
+    /// live from the provider; see `STARKNET_AUTO_DETECT_CHAIN_ID` below
+    pub chain_id: Felt,
+}
+
+/// Creates a StarknetContext from environment variables.
+///
+/// # Returns
+///
+/// * `StarknetContext` - Structure containing provider, signer, address, and chain id
+///
+/// # Environment Variables
+///
+/// * `STARKNET_RPC_URL` - URL of the Starknet JSON-RPC endpoint
+/// * `STARKNET_PRIVATE_KEY` - Private key for the Starknet account
+/// * `STARKNET_ACCOUNT_ADDRESS` - Address of the Starknet account
+/// * `STARKNET_NETWORK` - `mainnet`, `sepolia`, or `devnet`; defaults to `sepolia`
+/// * `STARKNET_AUTO_DETECT_CHAIN_ID` - when set to `true`, calls `starknet_chainId` on
+///   the provider instead of trusting `STARKNET_NETWORK`'s default chain id
+///
+/// # Errors
+///
+/// Returns a [`StarknetError::Config`] if a required environment variable is missing or
+/// cannot be parsed, or a [`StarknetError::Provider`] if chain id auto-detection fails.
+pub async fn starknet_call_context() -> Result<StarknetContext, StarknetError> {
+    let rpc_url = std::env::var("STARKNET_RPC_URL")
+        .map_err(|_| StarknetError::Config("missing STARKNET_RPC_URL env".into()))?;
+    let url = Url::parse(&rpc_url)
+        .map_err(|err| StarknetError::Config(format!("invalid STARKNET_RPC_URL: {err}")))?;
+    let provider = JsonRpcClient::new(HttpTransport::new(url));
+
+    let private_key = std::env::var("STARKNET_PRIVATE_KEY")
+        .map_err(|_| StarknetError::Config("missing STARKNET_PRIVATE_KEY env".into()))?;
+    let private_key = Felt::from_hex(&private_key)
+        .map_err(|err| StarknetError::Config(format!("invalid STARKNET_PRIVATE_KEY: {err}")))?;
+    let signer = LocalWallet::from(SigningKey::from_secret_scalar(private_key));
+
+    let address = std::env::var("STARKNET_ACCOUNT_ADDRESS")
+        .map_err(|_| StarknetError::Config("missing STARKNET_ACCOUNT_ADDRESS env".into()))?;
+    let address = Felt::from_hex(&address)
+        .map_err(|err| StarknetError::Config(format!("invalid STARKNET_ACCOUNT_ADDRESS: {err}")))?;
+
+    let auto_detect = std::env::var("STARKNET_AUTO_DETECT_CHAIN_ID")
+        .map(|value| value == "true")
+        .unwrap_or(false);
+    let chain_id = if auto_detect {
+        network::detect_chain_id(&provider).await?
+    } else {
+        Network::from_env()?.chain_id()
+    };
+
+    Ok(StarknetContext {
+        provider,
+        signer,
+        address,
+        chain_id,
+    })
+}
+
+/// Creates a SingleOwnerAccount from the provided components.
+///
+/// # Arguments
+///
+/// * `provider` - JSON-RPC client for Starknet node communication
+/// * `signer` - Wallet for transaction signing
+/// * `address` - Account address
+/// * `chain_id` - Starknet chain ID
+///
+/// # Returns
+///
+/// * `SingleOwnerAccount` - The initialized Starknet account
+pub fn starknet_account(
+    provider: JsonRpcClient<HttpTransport>,
+    signer: LocalWallet,
+    address: Felt,
+    chain_id: Felt,
+) -> StarknetAccount {
+    SingleOwnerAccount::new(provider, signer, address, chain_id, ExecutionEncoding::New)
+}
+
+/// Executes a Starknet transaction with the specified calls, paying fees according to
+/// `fee_settings`.
+///
+/// # Arguments
+///
+/// * `account` - The Starknet account used to execute the transaction
+/// * `call` - A vector of Call objects representing the function calls to execute
+/// * `fee_settings` - Which fee token/version to pay with, and the bounds to apply; see
+///   [`FeeSettings`]
+///
+/// # Errors
+///
+/// Returns a [`StarknetError`] if fee estimation or sending the transaction fails, or if
+/// `fee_settings` contains invalid (zero) bounds.
+pub async fn starknet_call(
+    account: &StarknetAccount,
+    call: Vec<Call>,
+    fee_settings: FeeSettings,
+) -> Result<InvokeTransactionResult, StarknetError> {
+    fee::execute_with_fee_settings(account, call, fee_settings).await
+}
@@ -1,165 +1,282 @@
-use starknet::{
-    accounts::{Account, ExecutionEncoding, SingleOwnerAccount},
-    core::{
-        chain_id,
-        types::{Call, Felt, InvokeTransactionResult},
-        utils::get_selector_from_name,
+use clap::{Args, Parser, Subcommand};
+use starknet::accounts::Account;
+use starknet::core::types::{BlockId, BlockTag, Call, Felt};
+use starknet::core::utils::get_selector_from_name;
+
+use starknet_caller::{
+    burner, calldata, deploy, query, starknet_account, starknet_call, starknet_call_context,
+    FeeSettings, StarknetError,
+};
+
+/// Default path for the burner keystore; override with `--keystore`.
+const DEFAULT_KEYSTORE_PATH: &str = "burners.json";
+
+/// Query and interact with Starknet contracts, mirroring the command split `sncast` uses.
+#[derive(Parser)]
+#[command(name = "starknet_caller")]
+struct Cli {
+    #[command(subcommand)]
+    command: Commands,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    /// Perform a read-only contract call (non-mutating `starknet_call`)
+    Call(CallArgs),
+    /// Send an invoke transaction to a contract
+    Invoke(InvokeArgs),
+    /// Declare and deploy a contract from a Sierra artifact
+    Deploy(DeployArgs),
+    /// Generate or deploy a burner (throwaway) account
+    Burner(BurnerArgs),
+}
+
+#[derive(Args)]
+struct BurnerArgs {
+    #[command(subcommand)]
+    command: BurnerCommands,
+}
+
+#[derive(Subcommand)]
+enum BurnerCommands {
+    /// Create a fresh burner and persist it to the keystore, without deploying it
+    Generate {
+        /// Path to the JSON keystore file
+        #[arg(long, default_value = DEFAULT_KEYSTORE_PATH)]
+        keystore: String,
     },
-    providers::{
-        Url,
-        jsonrpc::{HttpTransport, JsonRpcClient},
+    /// Send a DEPLOY_ACCOUNT transaction for a previously generated burner
+    Deploy {
+        /// Path to the JSON keystore file
+        #[arg(long, default_value = DEFAULT_KEYSTORE_PATH)]
+        keystore: String,
+        /// Address of the burner to deploy, as hex (0x...)
+        #[arg(long)]
+        address: String,
     },
-    signers::{LocalWallet, SigningKey},
-};
+}
+
+#[derive(Args)]
+struct CallArgs {
+    /// Contract address to call, as hex (0x...) or decimal
+    #[arg(long)]
+    contract: String,
+    /// Name of the entry point to call
+    #[arg(long)]
+    function: String,
+    /// Calldata felts, comma-separated hex (0x...) or decimal values
+    #[arg(long, value_delimiter = ',', default_value = "")]
+    calldata: Vec<String>,
+    /// Path to the contract's ABI JSON; when given, validates `--function`'s argument
+    /// count against it before sending the call
+    #[arg(long)]
+    abi: Option<String>,
+}
+
+#[derive(Args)]
+struct InvokeArgs {
+    /// Contract address to invoke, as hex (0x...) or decimal
+    #[arg(long)]
+    contract: String,
+    /// Name of the entry point to invoke
+    #[arg(long)]
+    function: String,
+    /// Calldata felts, comma-separated hex (0x...) or decimal values
+    #[arg(long, value_delimiter = ',', default_value = "")]
+    calldata: Vec<String>,
+    /// Path to the contract's ABI JSON; when given, validates `--function`'s argument
+    /// count against it before sending the transaction
+    #[arg(long)]
+    abi: Option<String>,
+}
+
+#[derive(Args)]
+struct DeployArgs {
+    /// Path to the compiled Sierra contract artifact
+    #[arg(long)]
+    contract: String,
+    /// Constructor calldata felts, comma-separated hex (0x...) or decimal values
+    #[arg(long, value_delimiter = ',', default_value = "")]
+    calldata: Vec<String>,
+    /// Salt used to derive the deployed address; defaults to zero
+    #[arg(long)]
+    salt: Option<String>,
+}
 
-/// A structure to hold Starknet connection context information.
-/// This groups the necessary components for interacting with Starknet.
-struct StarknetContext {
-    /// JSON-RPC client for communicating with a Starknet node
-    provider: JsonRpcClient<HttpTransport>,
-    /// Local wallet used for signing transactions
-    signer: LocalWallet,
-    /// Starknet account address
-    address: Felt,
-}
-
-/// Main function that demonstrates interacting with a Starknet smart contract.
-///
-/// This function:
-/// 1. Initializes the Starknet context from environment variables
-/// 2. Creates a Starknet account
-/// 3. Retrieves the contract address from environment
-/// 4. Executes a transaction to call the mint_lords function
-/// 5. Prints the transaction hash
-///
-/// # Environment Variables Required
-///
-/// * All variables required by `starknet_call_context()`
-/// * `STARKNET_CONTRACT_ADDRESS` - Address of the target contract
 #[tokio::main]
-async fn main() {
-    let context: StarknetContext = starknet_call_context();
-    let account: SingleOwnerAccount<JsonRpcClient<HttpTransport>, LocalWallet> = starknet_account(
+async fn main() -> Result<(), StarknetError> {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Commands::Call(args) => run_call(args).await,
+        Commands::Invoke(args) => run_invoke(args).await,
+        Commands::Deploy(args) => run_deploy(args).await,
+        Commands::Burner(args) => run_burner(args).await,
+    }
+}
+
+/// Handles `starknet_caller burner generate`/`burner deploy`.
+async fn run_burner(args: BurnerArgs) -> Result<(), StarknetError> {
+    match args.command {
+        BurnerCommands::Generate { keystore } => {
+            let new_burner = burner::generate_burner(burner::DEFAULT_ACCOUNT_CLASS_HASH);
+            burner::persist_burner(&keystore, &new_burner)?;
+            println!("Generated burner {:#064x}", new_burner.address);
+            println!(
+                "Fund it with STRK, then run `burner deploy --address {:#064x}`",
+                new_burner.address
+            );
+            Ok(())
+        }
+        BurnerCommands::Deploy { keystore, address } => {
+            let address = parse_felt(&address)?;
+            let burners = burner::load_keystore(&keystore)?;
+            let target = burners
+                .into_iter()
+                .find(|burner| burner.address == address)
+                .ok_or_else(|| {
+                    StarknetError::Config(format!("no burner {address:#064x} in {keystore}"))
+                })?;
+
+            let context = starknet_call_context().await?;
+            let result = burner::deploy_account(context.provider, &target, context.chain_id).await?;
+            println!(
+                "Deployed burner {:#064x}, transaction hash {:#064x}",
+                target.address, result.transaction_hash
+            );
+            Ok(())
+        }
+    }
+}
+
+/// Handles `starknet_caller call`: a non-mutating `starknet_call` against the latest
+/// block, printing the raw felts the entry point returned.
+async fn run_call(args: CallArgs) -> Result<(), StarknetError> {
+    let context = starknet_call_context().await?;
+    let contract = parse_felt(&args.contract)?;
+    let selector = get_selector_from_name(&args.function)
+        .map_err(|err| StarknetError::Config(format!("invalid function name: {err}")))?;
+    let calldata = parse_calldata(&args.calldata)?;
+    validate_against_abi(args.abi.as_deref(), &args.function, calldata.len())?;
+
+    let result = query::call(
+        &context.provider,
+        contract,
+        selector,
+        calldata,
+        BlockId::Tag(BlockTag::Latest),
+    )
+    .await?;
+
+    println!("{result:#?}");
+    Ok(())
+}
+
+/// Handles `starknet_caller invoke`: sends a single-call invoke transaction paid in STRK,
+/// estimating resource bounds automatically.
+async fn run_invoke(args: InvokeArgs) -> Result<(), StarknetError> {
+    let context = starknet_call_context().await?;
+    let mut account = starknet_account(
         context.provider,
         context.signer,
         context.address,
-        chain_id::SEPOLIA,
+        context.chain_id,
     );
-    let contract_address = Felt::from_hex(
-        &std::env::var("STARKNET_CONTRACT_ADDRESS")
-            .expect("cannot find STARKNET_CONTRACT_ADDRESS env"),
-    )
-    .unwrap();
-    let selector_name = "mint_lords";
+    account.set_block_id(BlockId::Tag(BlockTag::Pending));
+
+    let contract = parse_felt(&args.contract)?;
+    let selector = get_selector_from_name(&args.function)
+        .map_err(|err| StarknetError::Config(format!("invalid function name: {err}")))?;
+    let calldata = parse_calldata(&args.calldata)?;
+    validate_against_abi(args.abi.as_deref(), &args.function, calldata.len())?;
 
     let call = vec![Call {
-        to: contract_address,
-        selector: get_selector_from_name(selector_name).unwrap(),
-        calldata: vec![],
+        to: contract,
+        selector,
+        calldata,
     }];
-
-    let result = starknet_call(account, call).await;
+    let result = starknet_call(&account, call, FeeSettings::strk_auto()).await?;
 
     println!("Transaction hash: {:#064x}", result.transaction_hash);
+    Ok(())
 }
 
-/// Executes a Starknet transaction with the specified calls.
-///
-/// This function takes a Starknet account and a vector of Call objects, executes them
-/// as a single transaction, and returns the result of that transaction.
-///
-/// # Arguments
-///
-/// * `account` - The Starknet account used to execute the transaction
-/// * `call` - A vector of Call objects representing the function calls to execute
-///
-/// # Returns
-///
-/// * `InvokeTransactionResult` - The result of the transaction execution, including
-///   transaction hash and other relevant information
-///
-/// # Panics
-///
-/// * Panics if the transaction execution fails for any reason (e.g., insufficient
-///   balance, invalid function call, contract error)
-///
-/// # Example
-///
-/// ```
-/// let result = starknet_call(account, vec![Call {
-///     to: contract_address,
-///     selector: get_selector_from_name("mint_lords").unwrap(),
-///     calldata: vec![],
-/// }]).await;
-/// ```
-async fn starknet_call(
-    account: SingleOwnerAccount<JsonRpcClient<HttpTransport>, LocalWallet>,
-    call: Vec<Call>,
-) -> InvokeTransactionResult {
-    let result = account.execute_v3(call).send().await.unwrap();
-
-    result
-}
-
-/// Creates a StarknetContext from environment variables.
-///
-/// # Returns
-///
-/// * `StarknetContext` - Structure containing provider, signer, and address
-///
-/// # Environment Variables
-///
-/// * `STARKNET_RPC_URL` - URL of the Starknet JSON-RPC endpoint
-/// * `STARKNET_PRIVATE_KEY` - Private key for the Starknet account
-/// * `STARKNET_ACCOUNT_ADDRESS` - Address of the Starknet account
-///
-/// # Panics
-///
-/// * If any of the required environment variables are not set
-/// * If parsing the URL or hex values fails
-fn starknet_call_context() -> StarknetContext {
-    let provider = JsonRpcClient::new(HttpTransport::new(
-        Url::parse(&std::env::var("STARKNET_RPC_URL").expect("cannot find STARKNET_RPC_URL env"))
-            .unwrap(),
-    ));
-    let signer = LocalWallet::from(SigningKey::from_secret_scalar(
-        Felt::from_hex(
-            &std::env::var("STARKNET_PRIVATE_KEY").expect("cannot find STARKNET_PRIVATE_KEY env"),
-        )
-        .unwrap(),
-    ));
-    let address = Felt::from_hex(
-        &std::env::var("STARKNET_ACCOUNT_ADDRESS")
-            .expect("cannot find STARKNET_ACCOUNT_ADDRESS env"),
-    )
-    .unwrap();
+/// Handles `starknet_caller deploy`: declares the Sierra artifact at `--contract` (its
+/// matching `.casm.json` is expected alongside it) and deploys an instance of it.
+async fn run_deploy(args: DeployArgs) -> Result<(), StarknetError> {
+    let context = starknet_call_context().await?;
+    let mut account = starknet_account(
+        context.provider,
+        context.signer,
+        context.address,
+        context.chain_id,
+    );
+    account.set_block_id(BlockId::Tag(BlockTag::Pending));
+
+    let salt = match args.salt {
+        Some(salt) => parse_felt(&salt)?,
+        None => Felt::ZERO,
+    };
+    let constructor_calldata = parse_calldata(&args.calldata)?;
+    let casm_path = casm_path_for(&args.contract);
+    let compiled_class_hash = deploy::compiled_class_hash_from_file(&casm_path)?;
+
+    let (_, class_hash) =
+        deploy::declare_contract(&account, &args.contract, compiled_class_hash).await?;
+    println!("Declared class hash: {class_hash:#064x}");
+
+    let deployed_address =
+        deploy::deploy_contract(account, class_hash, constructor_calldata, salt).await?;
+    println!("Deployed at: {deployed_address:#064x}");
+    Ok(())
+}
+
+/// Swaps a Sierra artifact's `.sierra.json`/`.json` suffix for `.casm.json`, the layout
+/// `scarb`/`starknet-compile` produce a contract's pair of artifacts under.
+fn casm_path_for(sierra_path: &str) -> String {
+    sierra_path
+        .strip_suffix(".sierra.json")
+        .or_else(|| sierra_path.strip_suffix(".json"))
+        .map(|stem| format!("{stem}.casm.json"))
+        .unwrap_or_else(|| format!("{sierra_path}.casm.json"))
+}
+
+/// When `abi_path` is given, loads the contract ABI JSON at that path and checks that
+/// `function` expects exactly `arg_count` arguments, failing fast instead of sending a
+/// call the contract will revert. A no-op when `abi_path` is `None`.
+fn validate_against_abi(
+    abi_path: Option<&str>,
+    function: &str,
+    arg_count: usize,
+) -> Result<(), StarknetError> {
+    let Some(abi_path) = abi_path else {
+        return Ok(());
+    };
+    let contents = std::fs::read_to_string(abi_path)
+        .map_err(|err| StarknetError::Config(format!("failed to read ABI {abi_path}: {err}")))?;
+    let abi: serde_json::Value = serde_json::from_str(&contents)
+        .map_err(|err| StarknetError::Config(format!("failed to parse ABI {abi_path}: {err}")))?;
+    calldata::validate_arg_count(&abi, function, arg_count)
+}
 
-    StarknetContext {
-        provider,
-        signer,
-        address,
+/// Parses a hex (`0x...`) or decimal felt from a CLI argument.
+fn parse_felt(value: &str) -> Result<Felt, StarknetError> {
+    if value.starts_with("0x") || value.starts_with("0X") {
+        Felt::from_hex(value)
+            .map_err(|err| StarknetError::Config(format!("invalid felt \"{value}\": {err}")))
+    } else {
+        value
+            .parse()
+            .map_err(|_| StarknetError::Config(format!("invalid felt \"{value}\"")))
     }
 }
 
-/// Creates a SingleOwnerAccount from the provided components.
-///
-/// # Arguments
-///
-/// * `provider` - JSON-RPC client for Starknet node communication
-/// * `signer` - Wallet for transaction signing
-/// * `address` - Account address
-/// * `chain_id` - Starknet chain ID
-///
-/// # Returns
-///
-/// * `SingleOwnerAccount` - The initialized Starknet account
-fn starknet_account(
-    provider: JsonRpcClient<HttpTransport>,
-    signer: LocalWallet,
-    address: Felt,
-    chain_id: Felt,
-) -> SingleOwnerAccount<JsonRpcClient<HttpTransport>, LocalWallet> {
-    let account =
-        SingleOwnerAccount::new(provider, signer, address, chain_id, ExecutionEncoding::New);
-    account
+/// Parses a comma-split list of CLI calldata values, skipping empty entries so an unused
+/// `--calldata` flag doesn't produce a stray `[""]`.
+fn parse_calldata(values: &[String]) -> Result<Vec<Felt>, StarknetError> {
+    values
+        .iter()
+        .filter(|value| !value.is_empty())
+        .map(|value| parse_felt(value))
+        .collect()
 }
@@ -0,0 +1,65 @@
+use starknet::core::chain_id;
+use starknet::core::types::Felt;
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider};
+
+use crate::error::StarknetError;
+
+/// Which Starknet network to target.
+///
+/// Read from the `STARKNET_NETWORK` env var (`mainnet`, `sepolia`, or `devnet`), falling
+/// back to `Sepolia` if unset, since that's what this crate defaulted to before this
+/// network abstraction existed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Network {
+    Mainnet,
+    Sepolia,
+    Devnet,
+}
+
+impl Network {
+    /// Reads `STARKNET_NETWORK` from the environment, defaulting to [`Network::Sepolia`].
+    pub fn from_env() -> Result<Self, StarknetError> {
+        match std::env::var("STARKNET_NETWORK") {
+            Ok(value) => value.parse(),
+            Err(std::env::VarError::NotPresent) => Ok(Network::Sepolia),
+            Err(err) => Err(StarknetError::Config(format!(
+                "STARKNET_NETWORK is not valid unicode: {err}"
+            ))),
+        }
+    }
+
+    /// The chain id this network is expected to report, used as a fallback when
+    /// auto-detection is not requested.
+    pub fn chain_id(self) -> Felt {
+        match self {
+            Network::Mainnet => chain_id::MAINNET,
+            Network::Sepolia => chain_id::SEPOLIA,
+            // Local devnets are typically seeded with the sepolia chain id.
+            Network::Devnet => chain_id::SEPOLIA,
+        }
+    }
+}
+
+impl std::str::FromStr for Network {
+    type Err = StarknetError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "mainnet" => Ok(Network::Mainnet),
+            "sepolia" => Ok(Network::Sepolia),
+            "devnet" => Ok(Network::Devnet),
+            other => Err(StarknetError::Config(format!(
+                "unknown STARKNET_NETWORK \"{other}\" (expected mainnet, sepolia, or devnet)"
+            ))),
+        }
+    }
+}
+
+/// Asks the provider for its chain id via `starknet_chainId`, rather than assuming one
+/// from [`Network`]. Useful when `provider` points at a devnet or fork whose chain id
+/// doesn't match the network's usual default.
+pub async fn detect_chain_id(provider: &JsonRpcClient<HttpTransport>) -> Result<Felt, StarknetError> {
+    let chain_id = provider.chain_id().await?;
+    Ok(chain_id)
+}
@@ -0,0 +1,58 @@
+use starknet::core::types::{BlockId, ContractClass, Felt, FunctionCall, MaybePendingBlockWithTxs};
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider};
+
+use crate::error::StarknetError;
+
+/// Read-only query facade over the provider held in [`StarknetContext`](crate::StarknetContext).
+///
+/// Everything here is a plain `starknet_*` RPC call with no signing involved, so it takes
+/// a shared reference to the provider rather than an account.
+
+/// Reads a single storage slot of `contract` at `block`.
+pub async fn get_storage_at(
+    provider: &JsonRpcClient<HttpTransport>,
+    contract: Felt,
+    key: Felt,
+    block: BlockId,
+) -> Result<Felt, StarknetError> {
+    let value = provider.get_storage_at(contract, key, block).await?;
+    Ok(value)
+}
+
+/// Fetches a block, including its transactions, by id.
+pub async fn get_block_with_txs(
+    provider: &JsonRpcClient<HttpTransport>,
+    block: BlockId,
+) -> Result<MaybePendingBlockWithTxs, StarknetError> {
+    let block = provider.get_block_with_txs(block).await?;
+    Ok(block)
+}
+
+/// Performs a non-mutating `starknet_call` against `contract`, returning the raw felts
+/// the entry point returned instead of sending a transaction.
+pub async fn call(
+    provider: &JsonRpcClient<HttpTransport>,
+    contract: Felt,
+    selector: Felt,
+    calldata: Vec<Felt>,
+    block: BlockId,
+) -> Result<Vec<Felt>, StarknetError> {
+    let request = FunctionCall {
+        contract_address: contract,
+        entry_point_selector: selector,
+        calldata,
+    };
+    let result = provider.call(request, block).await?;
+    Ok(result)
+}
+
+/// Fetches the (Sierra or legacy) class definition for `class_hash` as seen at `block`.
+pub async fn get_contract_class(
+    provider: &JsonRpcClient<HttpTransport>,
+    class_hash: Felt,
+    block: BlockId,
+) -> Result<ContractClass, StarknetError> {
+    let class = provider.get_class(block, class_hash).await?;
+    Ok(class)
+}